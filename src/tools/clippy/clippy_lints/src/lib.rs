@@ -0,0 +1,7 @@
+mod operators;
+
+use rustc_lint::LintStore;
+
+pub fn register_plugins(store: &mut LintStore) {
+    store.register_late_pass(|| Box::new(operators::Operators));
+}
@@ -0,0 +1,189 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eq_expr_value;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use if_chain::if_chain;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, FloatTy};
+use rustc_span::source_map::Spanned;
+use rustc_span::sym;
+
+use super::DURATION_SUBSEC;
+
+pub(crate) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+) {
+    match op {
+        BinOpKind::Div => check_subsec_division(cx, expr, left, right),
+        BinOpKind::Add => {
+            check_duration_reassembly(cx, expr, left, right);
+            check_duration_as_float(cx, expr, left, right);
+        },
+        _ => {},
+    }
+}
+
+fn check_subsec_division<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, left: &'tcx Expr<'_>, right: &'tcx Expr<'_>) {
+    if_chain! {
+        if let ExprKind::MethodCall(method_path, args, _) = left.kind;
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&args[0]).peel_refs(), sym::Duration);
+        if let Some((Constant::Int(divisor), _)) = constant(cx, cx.typeck_results(), right);
+        then {
+            let suggested_fn = match (method_path.ident.as_str(), divisor) {
+                ("subsec_micros", 1_000) | ("subsec_nanos", 1_000_000) => "subsec_millis",
+                ("subsec_nanos", 1_000) => "subsec_micros",
+                _ => return,
+            };
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                DURATION_SUBSEC,
+                expr.span,
+                &format!("calling `{}()` is more concise than this calculation", suggested_fn),
+                "try",
+                format!(
+                    "{}.{}()",
+                    snippet_with_applicability(cx, args[0].span, "_", &mut applicability),
+                    suggested_fn
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Matches `d.as_secs() * N + d.subsec_*()`, in either operand order, against the whole-duration
+/// reassembly idioms that collapse to `as_millis()`, `as_micros()` or `as_nanos()`.
+fn check_duration_reassembly<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, left: &'tcx Expr<'_>, right: &'tcx Expr<'_>) {
+    if_chain! {
+        if let Some((secs_recv, multiplier, subsec_call)) = as_secs_mul_and_subsec_call(cx, left, right);
+        if let ExprKind::MethodCall(subsec_path, subsec_args, _) = peel_cast(subsec_call).kind;
+        if eq_expr_value(cx, secs_recv, &subsec_args[0]);
+        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(secs_recv).peel_refs(), sym::Duration);
+        then {
+            let suggested_fn = match (subsec_path.ident.as_str(), multiplier) {
+                ("subsec_millis", 1_000) => "as_millis",
+                ("subsec_micros", 1_000_000) => "as_micros",
+                ("subsec_nanos", 1_000_000_000) => "as_nanos",
+                _ => return,
+            };
+            // The matched sum has type `u64`, but `as_millis`/`as_micros`/`as_nanos` return
+            // `u128`, so this suggestion can change the expression's type and must not be
+            // auto-applied.
+            let mut applicability = Applicability::MaybeIncorrect;
+            span_lint_and_sugg(
+                cx,
+                DURATION_SUBSEC,
+                expr.span,
+                &format!("calling `{}()` is more concise than this calculation", suggested_fn),
+                "try",
+                format!(
+                    "{}.{}()",
+                    snippet_with_applicability(cx, secs_recv.span, "_", &mut applicability),
+                    suggested_fn
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
+/// If one of `left`/`right` is `<recv>.as_secs() * N`, returns the `as_secs` receiver, the
+/// multiplier `N`, and the other operand. The other operand is not inspected here; it may be any
+/// expression, e.g. a `subsec_*()` call behind a cast.
+fn as_secs_mul_and_subsec_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, u128, &'tcx Expr<'tcx>)> {
+    for (mul_expr, other) in [(left, right), (right, left)] {
+        if let ExprKind::Binary(Spanned { node: BinOpKind::Mul, .. }, mul_left, mul_right) = mul_expr.kind {
+            for (as_secs_expr, factor_expr) in [(mul_left, mul_right), (mul_right, mul_left)] {
+                if_chain! {
+                    if let ExprKind::MethodCall(path, args, _) = as_secs_expr.kind;
+                    if path.ident.as_str() == "as_secs";
+                    if let Some((Constant::Int(multiplier), _)) = constant(cx, cx.typeck_results(), factor_expr);
+                    then {
+                        return Some((&args[0], multiplier, other));
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Matches `d.as_secs() as fN + d.subsec_*() as fN / UNIT`, in either operand order, suggesting
+/// `as_secs_fN()`.
+///
+/// `subsec_nanos() / 1e9`, `subsec_micros() / 1e6` and `subsec_millis() / 1e3` are all accepted
+/// as the fractional term, since they're equivalent ways of expressing the fractional second.
+fn check_duration_as_float<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, left: &'tcx Expr<'_>, right: &'tcx Expr<'_>) {
+    for (secs_expr, div_expr) in [(left, right), (right, left)] {
+        if_chain! {
+            if let ExprKind::Cast(secs_call, _) = secs_expr.kind;
+            if let ExprKind::MethodCall(secs_path, secs_args, _) = secs_call.kind;
+            if secs_path.ident.as_str() == "as_secs";
+            if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(&secs_args[0]).peel_refs(), sym::Duration);
+            if let ty::Float(float_ty) = cx.typeck_results().expr_ty(secs_expr).kind();
+            if let ExprKind::Binary(Spanned { node: BinOpKind::Div, .. }, div_left, div_right) = div_expr.kind;
+            if let ExprKind::Cast(subsec_call, _) = div_left.kind;
+            if let ExprKind::MethodCall(subsec_path, subsec_args, _) = subsec_call.kind;
+            if eq_expr_value(cx, &secs_args[0], &subsec_args[0]);
+            if let Some(unit_divisor) = float_constant(cx, div_right);
+            then {
+                let expected_divisor = match subsec_path.ident.as_str() {
+                    "subsec_nanos" => 1_000_000_000.0,
+                    "subsec_micros" => 1_000_000.0,
+                    "subsec_millis" => 1_000.0,
+                    _ => return,
+                };
+                if (unit_divisor - expected_divisor).abs() > f64::EPSILON {
+                    return;
+                }
+                let suggested_fn = match float_ty {
+                    FloatTy::F64 => "as_secs_f64",
+                    FloatTy::F32 => "as_secs_f32",
+                };
+                let mut applicability = Applicability::MachineApplicable;
+                span_lint_and_sugg(
+                    cx,
+                    DURATION_SUBSEC,
+                    expr.span,
+                    &format!("calling `{}()` is more concise than this calculation", suggested_fn),
+                    "try",
+                    format!(
+                        "{}.{}()",
+                        snippet_with_applicability(cx, secs_args[0].span, "_", &mut applicability),
+                        suggested_fn
+                    ),
+                    applicability,
+                );
+                return;
+            }
+        }
+    }
+}
+
+/// Strips a single leading cast, e.g. to see through the `as u64` that's needed to add a
+/// `subsec_*()` call (which returns `u32`) to an `as_secs()` call (which returns `u64`).
+fn peel_cast<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let ExprKind::Cast(inner, _) = expr.kind { inner } else { expr }
+}
+
+/// Evaluates `expr` as a floating-point constant, regardless of whether it folds to an `f32` or
+/// an `f64`.
+fn float_constant(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<f64> {
+    match constant(cx, cx.typeck_results(), expr) {
+        Some((Constant::F64(f), _)) => Some(f),
+        Some((Constant::F32(f), _)) => Some(f64::from(f)),
+        _ => None,
+    }
+}
@@ -0,0 +1,53 @@
+mod duration_subsec;
+
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::source_map::Spanned;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calculation of subsecond microseconds or milliseconds
+    /// from other `Duration` methods, as well as for manually reassembling
+    /// a `Duration` into milliseconds, microseconds, nanoseconds, or fractional
+    /// seconds.
+    ///
+    /// ### Why is this bad?
+    /// It's more concise to call `Duration::subsec_micros()`,
+    /// `Duration::as_millis()`, or `Duration::as_secs_f64()` (among others)
+    /// than to calculate them by hand.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # let duration = Duration::new(5, 0);
+    /// let micros = duration.subsec_nanos() / 1_000;
+    /// let millis = duration.subsec_nanos() / 1_000_000;
+    /// let total_millis = duration.as_secs() * 1_000 + duration.subsec_millis() as u64;
+    /// let secs_f64 = duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # let duration = Duration::new(5, 0);
+    /// let micros = duration.subsec_micros();
+    /// let millis = duration.subsec_millis();
+    /// let total_millis = duration.as_millis();
+    /// let secs_f64 = duration.as_secs_f64();
+    /// ```
+    #[clippy::version = "pre 1.29.0"]
+    pub DURATION_SUBSEC,
+    complexity,
+    "checks for calculation of subsecond microseconds or milliseconds"
+}
+
+declare_lint_pass!(Operators => [DURATION_SUBSEC]);
+
+impl<'tcx> LateLintPass<'tcx> for Operators {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Binary(Spanned { node: op, .. }, left, right) = expr.kind {
+            duration_subsec::check(cx, expr, op, left, right);
+        }
+    }
+}